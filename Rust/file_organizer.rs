@@ -1,127 +1,534 @@
-use std::fs::{self, metadata, read_dir};
+use std::fs::{self, metadata, read_dir, File};
 use std::path::{Path, PathBuf};
-use std::io::{self, Write};
+use std::io::{self, Read};
 use std::collections::HashMap;
-use std::hash::{Hasher, DefaultHasher};
 use std::time::SystemTime;
+use rayon::prelude::*;
+use regex::Regex;
+
+/// 스캔 대상에서 어떤 파일/폴더를 건너뛸지 결정하는 필터 모음입니다.
+/// 필터는 스캔 단계에서 바로 적용되어, 제외된 파일은 해시 계산이나 이동 대상에 올라가지 않습니다.
+struct FileFilter {
+    /// 지정되어 있으면 이 확장자 목록에 속한 파일만 대상으로 삼습니다 (대소문자 구분 없음, 점 없이 비교).
+    allowed_extensions: Option<Vec<String>>,
+    /// 이름이나 경로가 이 패턴 중 하나라도 일치하면 디렉터리 전체를 건너뜁니다 (예: `.git`).
+    excluded_dirs: Vec<Regex>,
+    /// 이름이나 경로가 이 패턴 중 하나라도 일치하면 해당 파일을 건너뜁니다.
+    excluded_items: Vec<Regex>,
+}
+
+impl FileFilter {
+    fn is_dir_excluded(&self, path: &Path) -> bool {
+        let path_str = path.to_string_lossy();
+        self.excluded_dirs.iter().any(|re| re.is_match(&path_str))
+    }
+
+    fn is_file_allowed(&self, path: &Path) -> bool {
+        let path_str = path.to_string_lossy();
+        if self.excluded_items.iter().any(|re| re.is_match(&path_str)) {
+            return false;
+        }
+        if let Some(allowed) = &self.allowed_extensions {
+            let ext = path
+                .extension()
+                .map(|e| e.to_string_lossy().to_lowercase())
+                .unwrap_or_default();
+            return allowed.iter().any(|allowed_ext| allowed_ext.eq_ignore_ascii_case(&ext));
+        }
+        true
+    }
+}
+
+/// `organize_files` 실행 결과를 담는 통계/로그 모음입니다.
+/// 기존에는 `println!`과 지역 변수 카운터로 진행 상황을 흩뿌려 출력했지만,
+/// 이 구조체를 반환값으로 삼으면 `organize_files`를 호출부 로깅에 얽매이지 않는 라이브러리 함수로 쓸 수 있습니다.
+#[derive(Debug, Default)]
+struct OrganizeResults {
+    /// 진행 상황을 설명하는 일반 메시지.
+    messages: Vec<String>,
+    /// 개별 파일 처리 실패 등, 전체 실행을 막지는 않는 경고.
+    warnings: Vec<String>,
+    /// 복구 불가능하지는 않지만 기록해 둘 필요가 있는 오류.
+    errors: Vec<String>,
+    /// 필터를 통과해 실제로 검사한 파일 수.
+    checked_files: usize,
+    /// 순회 중 내려간(제외되지 않은) 폴더 수.
+    checked_folders: usize,
+    /// 필터에 걸려 건너뛴 파일 수.
+    ignored_files: usize,
+    /// 필터에 걸려 건너뛴 폴더 수.
+    ignored_folders: usize,
+    /// 중복으로 판정된 그룹 수.
+    duplicate_groups: usize,
+    /// 실제로 삭제된 중복 파일 수.
+    deleted_count: usize,
+    /// 보관용 디렉터리로 이동된 오래된 파일 수.
+    moved_count: usize,
+    /// 중복 그룹에서 원본을 제외한 나머지의 총 바이트 수 (회수 가능한 공간).
+    lost_space_bytes: u64,
+    /// 실제로 삭제를 수행해 회수한 바이트 수 (드라이런에서는 0).
+    reclaimed_bytes: u64,
+}
+
+/// `source_dir` 아래를 재귀적으로 내려가며 모든 파일 경로를 모읍니다.
+/// 생성되는 `old_files` 디렉터리는 이미 정리된 결과물이므로 다시 스캔하지 않도록 건너뜁니다.
+/// 필터에 걸린 파일/폴더는 수집하지 않고, 건너뛴 개수를 `results`에 누적합니다.
+fn collect_files_recursive(
+    dir: &Path,
+    old_files_dir: &Path,
+    filter: &FileFilter,
+    files: &mut Vec<PathBuf>,
+    results: &mut OrganizeResults,
+) -> io::Result<()> {
+    for entry in read_dir(dir)? {
+        let entry = entry?;
+        let path = entry.path();
+        if path == *old_files_dir {
+            continue;
+        }
+        if path.is_dir() {
+            if filter.is_dir_excluded(&path) {
+                results.ignored_folders += 1;
+                continue;
+            }
+            results.checked_folders += 1;
+            collect_files_recursive(&path, old_files_dir, filter, files, results)?;
+        } else if path.is_file() {
+            if filter.is_file_allowed(&path) {
+                files.push(path);
+                results.checked_files += 1;
+            } else {
+                results.ignored_files += 1;
+            }
+        }
+    }
+    Ok(())
+}
+
+/// 파일을 한 번에 메모리에 올리지 않도록 고정 크기 버퍼로 읽으며 blake3 해시를 계산합니다.
+/// SipHash(DefaultHasher)는 64비트라 충돌 시 서로 다른 파일을 중복으로 오인해 삭제할 위험이 있었지만,
+/// blake3는 충돌 확률이 무시할 수준이라 드라이런 없이 "원본 유지, 나머지 삭제"를 적용해도 안전합니다.
+fn hash_file(path: &Path) -> io::Result<String> {
+    const CHUNK_SIZE: usize = 16 * 1024;
+    let mut file = File::open(path)?;
+    let mut hasher = blake3::Hasher::new();
+    let mut buffer = [0u8; CHUNK_SIZE];
+    loop {
+        let bytes_read = file.read(&mut buffer)?;
+        if bytes_read == 0 {
+            break;
+        }
+        hasher.update(&buffer[..bytes_read]);
+    }
+    Ok(hasher.finalize().to_hex().to_string())
+}
+
+/// 중복 파일을 어떤 기준으로 판별할지 선택합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum CheckingMethod {
+    /// 파일 크기만 비교합니다. 빠르지만 크기가 같은 서로 다른 파일을 중복으로 오인할 수 있습니다.
+    Size,
+    /// 크기가 같은 파일들만 모아 내용을 해시로 비교합니다. 기본값이며 정확도가 높습니다.
+    Hash,
+}
+
+/// 중복 그룹에서 어떤 파일을 남기고 어떤 파일을 지울지 결정하는 정책입니다.
+/// 기존에는 `paths.remove(0)`으로 디렉터리 순회 순서에 따라 임의로 원본을 골랐지만,
+/// 수정 시각을 기준으로 삼아 사용자가 의도적으로 결과를 고를 수 있게 합니다.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum KeepPolicy {
+    /// 그룹에서 가장 최근에 수정된 파일만 남기고 나머지를 모두 지웁니다.
+    KeepNewest,
+    /// 그룹에서 가장 오래전에 수정된 파일만 남기고 나머지를 모두 지웁니다.
+    KeepOldest,
+    /// 가장 최근에 수정된 파일 한 개만 지우고 나머지는 그대로 둡니다.
+    RemoveOneNewest,
+    /// 가장 오래전에 수정된 파일 한 개만 지우고 나머지는 그대로 둡니다.
+    RemoveOneOldest,
+}
+
+/// 중복 파일 그룹을 수정 시각 기준으로 정렬하고, 선택된 정책에 따라 삭제할 경로 목록을 돌려줍니다.
+/// 반환되는 벡터에 포함되지 않은 파일은 전부 보존됩니다.
+fn select_duplicates_to_remove(paths: &mut [PathBuf], policy: KeepPolicy) -> io::Result<Vec<PathBuf>> {
+    paths.sort_by_key(|path| {
+        metadata(path)
+            .and_then(|m| m.modified())
+            .unwrap_or(SystemTime::UNIX_EPOCH)
+    });
+    // 정렬 후 paths[0]이 가장 오래된 파일, paths[last]가 가장 최근 파일입니다.
+    let to_remove = match policy {
+        KeepPolicy::KeepNewest => paths[..paths.len() - 1].to_vec(),
+        KeepPolicy::KeepOldest => paths[1..].to_vec(),
+        KeepPolicy::RemoveOneNewest => vec![paths[paths.len() - 1].clone()],
+        KeepPolicy::RemoveOneOldest => vec![paths[0].clone()],
+    };
+    Ok(to_remove)
+}
+
+/// `30d`/`6m`/`1y` 같은 문자열을 `Duration`으로 변환합니다. 마지막 글자가 단위(일/월/년)이고
+/// 앞부분이 숫자여야 합니다. 월은 30일, 년은 365일로 근사합니다.
+fn parse_duration(input: &str) -> Result<std::time::Duration, String> {
+    // 문자 단위로 마지막 글자를 떼어내므로, 빈 입력이나 마지막 글자가 멀티바이트여도 경계 밖 슬라이싱이 없습니다.
+    let mut chars = input.chars();
+    let unit = chars
+        .next_back()
+        .ok_or_else(|| "Invalid duration '': expected a number followed by d/m/y".to_string())?;
+    let number_part = chars.as_str();
+    let amount: u64 = number_part
+        .parse()
+        .map_err(|_| format!("Invalid duration '{}': expected a number followed by d/m/y", input))?;
+    let days_per_unit = match unit {
+        'd' => 1,
+        'm' => 30,
+        'y' => 365,
+        _ => return Err(format!("Invalid duration unit in '{}': expected d, m, or y", input)),
+    };
+    Ok(std::time::Duration::from_secs(amount * days_per_unit * 24 * 60 * 60))
+}
+
+/// `path`를 `old_files_dir` 아래로 옮길 때 쓸 목적지 경로를 계산합니다.
+/// `source_dir` 기준 상대 경로를 그대로 보존해 하위 디렉터리 구조가 평탄화되지 않게 하고,
+/// 그래도 같은 경로가 이미 존재하면(경쟁 조건 등) 번호를 덧붙여 충돌을 피합니다.
+fn destination_for_old_file(source_dir: &Path, old_files_dir: &Path, path: &Path) -> io::Result<PathBuf> {
+    let relative = path.strip_prefix(source_dir).unwrap_or(path);
+    let mut destination = old_files_dir.join(relative);
+    if let Some(parent) = destination.parent() {
+        fs::create_dir_all(parent)?;
+    }
+
+    if destination.exists() {
+        let stem = destination.file_stem().unwrap_or_default().to_os_string();
+        let extension = destination.extension().map(|e| e.to_os_string());
+        let parent = destination.parent().map(PathBuf::from).unwrap_or_default();
+        let mut suffix = 1u32;
+        loop {
+            let mut candidate_name = stem.clone();
+            candidate_name.push(format!("_{}", suffix));
+            if let Some(extension) = &extension {
+                candidate_name.push(".");
+                candidate_name.push(extension);
+            }
+            let candidate = parent.join(candidate_name);
+            if !candidate.exists() {
+                destination = candidate;
+                break;
+            }
+            suffix += 1;
+        }
+    }
+
+    Ok(destination)
+}
 
 /// 주어진 경로에 있는 파일을 정리하고, 중복 파일이나 오래된 파일을 처리합니다.
-fn organize_files(source_dir: &Path, dry_run: bool) -> io::Result<()> {
+/// 출력 대신 [`OrganizeResults`]를 반환하므로, 호출자가 직접 메시지를 출력하거나
+/// 다른 방식으로 로깅할 수 있는 라이브러리 함수로 사용할 수 있습니다.
+fn organize_files(
+    source_dir: &Path,
+    dry_run: bool,
+    checking_method: CheckingMethod,
+    keep_policy: KeepPolicy,
+    filter: &FileFilter,
+    min_size: u64,
+    older_than: std::time::Duration,
+) -> io::Result<OrganizeResults> {
+    let mut results = OrganizeResults::default();
+
     if !source_dir.exists() || !source_dir.is_dir() {
-        println!("Error: The source directory does not exist or is not a directory.");
-        return Ok(());
+        results.errors.push("The source directory does not exist or is not a directory.".to_string());
+        return Ok(results);
     }
 
-    println!("--- File Organizer Started ---");
+    results.messages.push("--- File Organizer Started ---".to_string());
     if dry_run {
-        println!("*** Dry Run Mode Enabled: No files will be moved or deleted. ***");
+        results.messages.push("*** Dry Run Mode Enabled: No files will be moved or deleted. ***".to_string());
     }
 
-    let mut file_map: HashMap<u64, Vec<PathBuf>> = HashMap::new();
-    let mut deleted_count = 0;
-    let mut moved_count = 0;
+    let old_files_dir = source_dir.join("old_files");
 
-    // 1. 디렉토리를 순회하며 파일 해시맵을 생성
-    println!("Scanning files for duplicates...");
-    for entry in read_dir(source_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        if path.is_file() {
-            let file_meta = metadata(&path)?;
-            if file_meta.len() > 0 {
-                let mut hasher = DefaultHasher::new();
-                let file_content = fs::read(&path)?;
-                hasher.write(&file_content);
-                let file_hash = hasher.finish();
-                file_map.entry(file_hash).or_insert_with(Vec::new).push(path.clone());
+    // 하위 디렉터리까지 재귀적으로 내려가며 파일 목록을 한 번에 모읍니다.
+    results.messages.push("Scanning files recursively...".to_string());
+    let mut all_files = Vec::new();
+    collect_files_recursive(source_dir, &old_files_dir, filter, &mut all_files, &mut results)?;
+    results.messages.push(format!(
+        "Ignored {} files and {} folders due to filters.",
+        results.ignored_files, results.ignored_folders
+    ));
+
+    // 1단계: 파일 크기로 1차 분류하여, 크기가 겹치는 파일이 없으면 아예 읽지 않습니다.
+    results.messages.push("Grouping files by size...".to_string());
+    let mut size_map: HashMap<u64, Vec<PathBuf>> = HashMap::new();
+    for path in &all_files {
+        let file_meta = match metadata(path) {
+            Ok(file_meta) => file_meta,
+            Err(e) => {
+                results.warnings.push(format!("Failed to read metadata for {}: {}", path.display(), e));
+                continue;
+            }
+        };
+        if file_meta.len() > 0 && file_meta.len() >= min_size {
+            size_map.entry(file_meta.len()).or_default().push(path.clone());
+        }
+    }
+
+    // 크기가 유일한 파일은 중복일 수 없으므로 후보에서 제외합니다.
+    let candidates: Vec<(u64, Vec<PathBuf>)> = size_map
+        .into_iter()
+        .filter(|(_, paths)| paths.len() > 1)
+        .collect();
+
+    let mut file_map: HashMap<String, Vec<PathBuf>> = HashMap::new();
+    match checking_method {
+        CheckingMethod::Size => {
+            // 크기만으로 충분하다고 판단하는 빠른 모드: 크기를 그대로 키로 사용합니다.
+            results.messages.push("Checking method: size only (faster, less accurate).".to_string());
+            for (size, paths) in candidates {
+                file_map.insert(size.to_string(), paths);
+            }
+        }
+        CheckingMethod::Hash => {
+            // 2단계: 크기가 같은 후보 그룹에 한해서만, 해시 계산을 스레드 풀에 분산해 병렬로 수행합니다.
+            results.messages.push("Checking method: content hash (default, accurate).".to_string());
+            let candidate_paths: Vec<PathBuf> = candidates.into_iter().flat_map(|(_, paths)| paths).collect();
+            // 개별 파일 해시 실패(권한 문제, 경쟁 상태로 사라진 파일 등)는 경고로 기록하고 그 파일만 건너뜁니다.
+            let hashed: Vec<Result<(String, PathBuf), (PathBuf, io::Error)>> = candidate_paths
+                .into_par_iter()
+                .map(|path| match hash_file(&path) {
+                    Ok(file_hash) => Ok((file_hash, path)),
+                    Err(e) => Err((path, e)),
+                })
+                .collect();
+            for outcome in hashed {
+                match outcome {
+                    Ok((file_hash, path)) => {
+                        file_map.entry(file_hash).or_default().push(path);
+                    }
+                    Err((path, e)) => {
+                        results.warnings.push(format!("Failed to hash {}: {}", path.display(), e));
+                    }
+                }
             }
         }
     }
 
-    // 2. 중복 파일 삭제 (하나의 원본을 제외하고)
-    println!("Checking for duplicate files...");
+    // 2. 중복 파일 삭제 (정책에 따라 고른 파일만)
+    results.messages.push("Checking for duplicate files...".to_string());
     for (_hash, paths) in file_map.iter_mut() {
         if paths.len() > 1 {
-            println!("\nFound duplicates for hash {:x}", _hash);
-            let original = paths.remove(0);
-            println!("- Keeping original: {}", original.display());
+            results.duplicate_groups += 1;
+            results.messages.push(format!("Found duplicates for key {}", _hash));
+            let to_remove = select_duplicates_to_remove(paths, keep_policy)?;
+            for extra_copy in &paths[1..] {
+                results.lost_space_bytes += metadata(extra_copy).map(|m| m.len()).unwrap_or(0);
+            }
 
-            for duplicate in paths.iter() {
-                println!("- Deleting duplicate: {}", duplicate.display());
+            for duplicate in &to_remove {
+                let size = metadata(duplicate).map(|m| m.len()).unwrap_or(0);
+                results.messages.push(format!("- Deleting duplicate: {}", duplicate.display()));
                 if !dry_run {
-                    fs::remove_file(duplicate)?;
-                    deleted_count += 1;
+                    match fs::remove_file(duplicate) {
+                        Ok(()) => {
+                            results.deleted_count += 1;
+                            results.reclaimed_bytes += size;
+                        }
+                        Err(e) => {
+                            results.warnings.push(format!(
+                                "Failed to delete {}: {}",
+                                duplicate.display(),
+                                e
+                            ));
+                        }
+                    }
                 }
             }
         }
     }
-    println!("Found and processed {} duplicate files.", deleted_count);
-    
+    results.messages.push(format!("Found and processed {} duplicate files.", results.deleted_count));
+
     // 3. 오래된 파일 정리 (1년 이상 수정되지 않은 파일)
-    println!("\nChecking for old files (older than 1 year)...");
+    // 기준 기간은 `older_than` 인자로 정해지며, 1년은 `main`에서 쓰는 기본값입니다.
+    results.messages.push(format!("Checking for old files (older than {:?})...", older_than));
     let now = SystemTime::now();
-    let one_year_ago = now - std::time::Duration::from_secs(365 * 24 * 60 * 60);
-    
-    let old_files_dir = source_dir.join("old_files");
+    let threshold = now - older_than;
+
     if !old_files_dir.exists() {
         if !dry_run {
             fs::create_dir(&old_files_dir)?;
-            println!("Created directory for old files: {}", old_files_dir.display());
+            results.messages.push(format!("Created directory for old files: {}", old_files_dir.display()));
         } else {
-            println!("Would create directory for old files: {}", old_files_dir.display());
+            results.messages.push(format!("Would create directory for old files: {}", old_files_dir.display()));
         }
     }
 
-    let mut entries_to_process = Vec::new();
-    for entry in read_dir(source_dir)? {
-        let entry = entry?;
-        let path = entry.path();
-        entries_to_process.push(path);
-    }
-    
-    for path in entries_to_process {
-        if path.is_file() {
-            if let Ok(file_meta) = metadata(&path) {
-                if let Ok(modified_time) = file_meta.modified() {
-                    if modified_time < one_year_ago {
-                        if !dry_run {
-                            let new_path = old_files_dir.join(path.file_name().unwrap());
-                            println!("Moving old file: {} -> {}", path.display(), new_path.display());
-                            fs::rename(&path, &new_path)?;
-                            moved_count += 1;
-                        } else {
-                            println!("Would move old file: {}", path.display());
+    for path in &all_files {
+        if let Ok(file_meta) = metadata(path) {
+            if let Ok(modified_time) = file_meta.modified() {
+                if modified_time < threshold {
+                    if !dry_run {
+                        match destination_for_old_file(source_dir, &old_files_dir, path) {
+                            Ok(new_path) => match fs::rename(path, &new_path) {
+                                Ok(()) => {
+                                    results.messages.push(format!("Moving old file: {} -> {}", path.display(), new_path.display()));
+                                    results.moved_count += 1;
+                                }
+                                Err(e) => {
+                                    results.warnings.push(format!("Failed to move {}: {}", path.display(), e));
+                                }
+                            },
+                            Err(e) => {
+                                results.warnings.push(format!(
+                                    "Failed to prepare destination for {}: {}",
+                                    path.display(),
+                                    e
+                                ));
+                            }
                         }
+                    } else {
+                        results.messages.push(format!("Would move old file: {}", path.display()));
                     }
                 }
             }
         }
     }
-    println!("Found and processed {} old files.", moved_count);
+    results.messages.push(format!("Found and processed {} old files.", results.moved_count));
 
-    println!("\n--- File Organizer Finished ---");
-    Ok(())
+    results.messages.push("--- File Organizer Finished ---".to_string());
+    Ok(results)
 }
 
 fn main() {
     let args: Vec<String> = std::env::args().collect();
     let mut source_dir = PathBuf::new();
     let mut dry_run = false;
+    let mut checking_method = CheckingMethod::Hash;
+    let mut keep_policy = KeepPolicy::KeepOldest;
+    let mut allowed_extensions: Option<Vec<String>> = None;
+    let mut excluded_dirs = Vec::new();
+    let mut excluded_items = Vec::new();
+    let mut min_size: u64 = 0;
+    let mut older_than = std::time::Duration::from_secs(365 * 24 * 60 * 60);
 
     // 명령줄 인자 파싱
     if args.len() < 2 {
-        println!("Usage: {} <directory> [--dry-run]", args[0]);
+        println!(
+            "Usage: {} <directory> [--dry-run] [--size-only] \
+             [--keep-policy <keep-newest|keep-oldest|remove-one-newest|remove-one-oldest>] \
+             [--extensions <jpg,jpeg,mp4>] [--exclude-dir <regex>] [--exclude-item <regex>] \
+             [--min-size <bytes>] [--older-than <30d|6m|1y>]",
+            args[0]
+        );
         return;
     }
 
     source_dir.push(&args[1]);
 
-    if args.len() > 2 && args[2] == "--dry-run" {
-        dry_run = true;
+    let mut i = 2;
+    while i < args.len() {
+        match args[i].as_str() {
+            "--dry-run" => dry_run = true,
+            "--size-only" => checking_method = CheckingMethod::Size,
+            "--keep-policy" => {
+                i += 1;
+                keep_policy = match args.get(i).map(|s| s.as_str()) {
+                    Some("keep-newest") => KeepPolicy::KeepNewest,
+                    Some("keep-oldest") => KeepPolicy::KeepOldest,
+                    Some("remove-one-newest") => KeepPolicy::RemoveOneNewest,
+                    Some("remove-one-oldest") => KeepPolicy::RemoveOneOldest,
+                    other => {
+                        println!("Unknown --keep-policy value: {:?}", other);
+                        return;
+                    }
+                };
+            }
+            "--extensions" => {
+                i += 1;
+                match args.get(i) {
+                    Some(list) => {
+                        allowed_extensions =
+                            Some(list.split(',').map(|s| s.trim().to_lowercase()).collect());
+                    }
+                    None => {
+                        println!("--extensions requires a comma-separated list");
+                        return;
+                    }
+                }
+            }
+            "--exclude-dir" => {
+                i += 1;
+                match args.get(i).and_then(|pattern| Regex::new(pattern).ok()) {
+                    Some(re) => excluded_dirs.push(re),
+                    None => {
+                        println!("--exclude-dir requires a valid regex");
+                        return;
+                    }
+                }
+            }
+            "--exclude-item" => {
+                i += 1;
+                match args.get(i).and_then(|pattern| Regex::new(pattern).ok()) {
+                    Some(re) => excluded_items.push(re),
+                    None => {
+                        println!("--exclude-item requires a valid regex");
+                        return;
+                    }
+                }
+            }
+            "--min-size" => {
+                i += 1;
+                match args.get(i).and_then(|s| s.parse::<u64>().ok()) {
+                    Some(bytes) => min_size = bytes,
+                    None => {
+                        println!("--min-size requires a number of bytes");
+                        return;
+                    }
+                }
+            }
+            "--older-than" => {
+                i += 1;
+                match args.get(i).map(|s| parse_duration(s)) {
+                    Some(Ok(duration)) => older_than = duration,
+                    Some(Err(e)) => {
+                        println!("{}", e);
+                        return;
+                    }
+                    None => {
+                        println!("--older-than requires a value like 30d, 6m, or 1y");
+                        return;
+                    }
+                }
+            }
+            _ => {}
+        }
+        i += 1;
     }
 
+    let filter = FileFilter {
+        allowed_extensions,
+        excluded_dirs,
+        excluded_items,
+    };
+
     // 파일 정리 함수 실행
-    if let Err(e) = organize_files(&source_dir, dry_run) {
-        eprintln!("An error occurred: {}", e);
+    match organize_files(&source_dir, dry_run, checking_method, keep_policy, &filter, min_size, older_than) {
+        Ok(results) => {
+            for message in &results.messages {
+                println!("{}", message);
+            }
+            for warning in &results.warnings {
+                println!("Warning: {}", warning);
+            }
+            for error in &results.errors {
+                println!("Error: {}", error);
+            }
+            println!(
+                "Checked {} files in {} folders, {} duplicate groups, {} bytes reclaimable, {} bytes reclaimed.",
+                results.checked_files,
+                results.checked_folders,
+                results.duplicate_groups,
+                results.lost_space_bytes,
+                results.reclaimed_bytes
+            );
+        }
+        Err(e) => eprintln!("An error occurred: {}", e),
     }
 }
\ No newline at end of file